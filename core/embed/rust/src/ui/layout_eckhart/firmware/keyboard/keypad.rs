@@ -0,0 +1,163 @@
+use crate::ui::{
+    component::{Component, Event, EventCtx},
+    event::TouchEvent,
+    geometry::{Alignment, Offset, Rect},
+    shape::{Bar, Renderer, Text},
+};
+
+use super::{common::KEYBOARD_INPUT_RADIUS, theme};
+
+/// Number of multi-tap letter-group keys on the keypad.
+pub const KEY_COUNT: usize = 8;
+
+/// The multi-tap letter groups, in their natural (unscrambled) order.
+const KEY_GROUPS: [&str; KEY_COUNT] = ["abc", "def", "ghi", "jkl", "mno", "pqrs", "tuv", "wxyz"];
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum ButtonState {
+    Hidden,
+    Disabled,
+    Enabled,
+}
+
+/// One `ButtonState` per letter-group key, so e.g. guided wordlist entry can
+/// disable individual groups that can't continue the current prefix instead
+/// of only being able to grey out the whole keypad at once.
+pub type KeyStates = [ButtonState; KEY_COUNT];
+
+pub struct KeypadState {
+    pub back: ButtonState,
+    pub erase: ButtonState,
+    pub cancel: ButtonState,
+    pub confirm: ButtonState,
+    pub keys: KeyStates,
+    /// Single key temporarily overridden to a different state than the rest
+    /// of `keys`, e.g. to keep the pending multi-tap key tappable while the
+    /// other keys are disabled.
+    pub override_key: Option<(usize, ButtonState)>,
+    /// Permutation mapping each rendered slot to the `KEY_GROUPS` entry shown
+    /// there, so a scrambled `PassphraseInput` renders and resolves taps
+    /// consistently. `None` renders groups in their natural order.
+    pub key_order: Option<[u8; KEY_COUNT]>,
+}
+
+/// Renders the 8 multi-tap letter-group keys and translates taps back to the
+/// underlying group index via `KeypadState::key_order`, so the rendered
+/// label a user taps always matches the group that gets entered, scrambled
+/// or not.
+pub struct Keypad {
+    area: Rect,
+    state: KeypadState,
+}
+
+impl Keypad {
+    pub fn new() -> Self {
+        Self {
+            area: Rect::zero(),
+            state: KeypadState {
+                back: ButtonState::Hidden,
+                erase: ButtonState::Hidden,
+                cancel: ButtonState::Hidden,
+                confirm: ButtonState::Disabled,
+                keys: [ButtonState::Enabled; KEY_COUNT],
+                override_key: None,
+                key_order: None,
+            },
+        }
+    }
+
+    /// Called by the owning screen whenever the driving `StringInput`
+    /// reports its keypad state has changed.
+    pub fn update(&mut self, state: KeypadState) {
+        self.state = state;
+    }
+
+    fn key_rect(&self, slot: usize) -> Rect {
+        let cell = Offset::new(self.area.width() / 4, self.area.height() / 2);
+        let col = (slot % 4) as i16;
+        let row = (slot / 4) as i16;
+        let origin = self
+            .area
+            .top_left()
+            .ofs(Offset::new(cell.x * col, cell.y * row));
+        Rect::from_top_left_and_size(origin, cell)
+    }
+
+    /// The underlying `KEY_GROUPS` index rendered at `slot`.
+    fn group_at(&self, slot: usize) -> usize {
+        self.state
+            .key_order
+            .map_or(slot, |order| order[slot] as usize)
+    }
+
+    /// `slot`'s effective state, honoring `override_key` over the per-key
+    /// `keys` array. Both are indexed by the group rendered at `slot`.
+    fn state_at(&self, slot: usize) -> ButtonState {
+        let group = self.group_at(slot);
+        match self.state.override_key {
+            Some((key, state)) if key == group => state,
+            _ => self.state.keys[group],
+        }
+    }
+}
+
+pub enum KeypadMsg {
+    Key(usize),
+    Back,
+    Erase,
+    Cancel,
+    Confirm,
+}
+
+impl Component for Keypad {
+    type Msg = KeypadMsg;
+
+    fn place(&mut self, bounds: Rect) -> Rect {
+        self.area = bounds;
+        bounds
+    }
+
+    fn event(&mut self, _ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
+        let Event::Touch(TouchEvent::TouchEnd(pos)) = event else {
+            return None;
+        };
+        for slot in 0..KEY_COUNT {
+            if matches!(self.state_at(slot), ButtonState::Enabled)
+                && self.key_rect(slot).contains(pos)
+            {
+                // `group_at` resolves the tapped slot back to the underlying
+                // group regardless of rendered (possibly scrambled) order,
+                // so callers always see the group the user actually meant to
+                // pick.
+                return Some(KeypadMsg::Key(self.group_at(slot)));
+            }
+        }
+        None
+    }
+
+    fn render<'s>(&self, target: &mut impl Renderer<'s>) {
+        for slot in 0..KEY_COUNT {
+            if matches!(self.state_at(slot), ButtonState::Hidden) {
+                continue;
+            }
+
+            let rect = self.key_rect(slot);
+            let label = KEY_GROUPS[self.group_at(slot)];
+            let dimmed = matches!(self.state_at(slot), ButtonState::Disabled);
+
+            Bar::new(rect)
+                .with_bg(theme::GREY_SUPER_DARK)
+                .with_radius(KEYBOARD_INPUT_RADIUS)
+                .render(target);
+
+            Text::new(rect.center(), label, theme::TEXT_REGULAR.text_font)
+                .with_align(Alignment::Center)
+                .with_fg(if dimmed {
+                    theme::GREY_SUPER_DARK
+                } else {
+                    theme::TEXT_REGULAR.text_color
+                })
+                .render(target);
+        }
+    }
+}
@@ -0,0 +1,266 @@
+use crate::{
+    strutil::TString,
+    time::Duration,
+    trezorhal::{bip39, slip39},
+    ui::{
+        component::{
+            text::{common::TextBox, layout::LineBreaking, TextStyle},
+            Component, Event, EventCtx, Timer,
+        },
+        geometry::{Alignment, Rect},
+        shape::{Renderer, Text},
+        util::DisplayStyle,
+    },
+};
+
+use super::super::{
+    keyboard::{
+        common::MultiTapKeyboard,
+        keypad::{ButtonState, KeypadState},
+    },
+    theme, StringInput, StringInputMsg,
+};
+
+/// Bitmask over the 26 lowercase letters, bit `n` set means `b'a' + n` can
+/// legally follow the given prefix towards at least one wordlist entry.
+type LetterMask = u32;
+
+/// Letters reachable via the multi-tap keys, in key order, mirroring the
+/// grouping `MultiTapKeyboard` already renders for alphabetic entry.
+const KEY_GROUPS: [&str; 8] = ["abc", "def", "ghi", "jkl", "mno", "pqrs", "tuv", "wxyz"];
+
+fn letter_mask(letter: char) -> LetterMask {
+    1 << (letter as u8 - b'a')
+}
+
+/// Which recovery-word wordlist a `MnemonicInput` guides entry against.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum WordlistKind {
+    Bip39,
+    Slip39,
+}
+
+impl WordlistKind {
+    fn words(self) -> &'static [&'static str] {
+        match self {
+            WordlistKind::Bip39 => bip39::wordlist(),
+            WordlistKind::Slip39 => slip39::wordlist(),
+        }
+    }
+}
+
+/// Bitmask over the 26 letters that can legally follow `prefix` towards a
+/// valid word in `wordlist`.
+fn word_completion_mask(prefix: &str, wordlist: WordlistKind) -> LetterMask {
+    let mut mask = 0;
+    for word in wordlist.words() {
+        if let Some(next) = word.strip_prefix(prefix).and_then(|rest| rest.chars().next()) {
+            mask |= letter_mask(next);
+        }
+    }
+    mask
+}
+
+/// The unique entry in `wordlist` starting with `prefix`, if the prefix is no
+/// longer ambiguous.
+fn complete_word(prefix: &str, wordlist: WordlistKind) -> Option<&'static str> {
+    let mut matches = wordlist.words().iter().filter(|word| word.starts_with(prefix));
+    let first = matches.next()?;
+    matches.next().is_none().then_some(*first)
+}
+
+/// Guided BIP39/SLIP39 recovery word entry. Drives the same multi-tap keypad
+/// as `PassphraseInput`, but greys out letters that cannot continue a valid
+/// wordlist entry and offers to auto-finish the word once its prefix is
+/// unambiguous.
+pub struct MnemonicInput {
+    area: Rect,
+    textbox: TextBox,
+    display_style: DisplayStyle,
+    last_char_timer: Timer,
+    multi_tap: MultiTapKeyboard,
+    allow_cancel: bool,
+    wordlist: WordlistKind,
+}
+
+impl MnemonicInput {
+    const MAX_WORD_LEN: usize = 8;
+    const STYLE: TextStyle =
+        theme::TEXT_REGULAR.with_line_breaking(LineBreaking::BreakWordsNoHyphen);
+    const LAST_DIGIT_TIMEOUT: Duration = Duration::from_secs(1);
+
+    pub fn new(wordlist: WordlistKind, allow_cancel: bool) -> Self {
+        Self {
+            area: Rect::zero(),
+            textbox: TextBox::empty(Self::MAX_WORD_LEN),
+            display_style: DisplayStyle::Hidden,
+            last_char_timer: Timer::new(),
+            multi_tap: MultiTapKeyboard::new(),
+            allow_cancel,
+            wordlist,
+        }
+    }
+
+    /// The unique word completion for what has been typed so far, if any.
+    fn pending_completion(&self) -> Option<&'static str> {
+        complete_word(self.content(), self.wordlist)
+    }
+
+    /// Called by the keypad when its confirm button is tapped (mirroring how
+    /// `on_key_click`/`on_erase` are driven explicitly by the keypad rather
+    /// than inferred from raw touch events). Returns the completed word and
+    /// clears the textbox so it's immediately ready for the next one; `None`
+    /// if the current prefix is still ambiguous and there's nothing to
+    /// confirm yet.
+    pub fn confirm_word(&mut self, ctx: &mut EventCtx) -> Option<TString<'static>> {
+        let word = self.pending_completion()?;
+        self.multi_tap.clear_pending_state(ctx);
+        self.textbox.clear(ctx);
+        self.display_style = DisplayStyle::Hidden;
+        Some(word.into())
+    }
+}
+
+impl StringInput for MnemonicInput {
+    fn on_key_click(&mut self, ctx: &mut EventCtx, idx: usize, text: TString<'static>) {
+        let edit = text.map(|c| self.multi_tap.click_key(ctx, idx, c));
+        self.textbox.apply(ctx, edit);
+        if text.len() == 1 {
+            self.display_style = DisplayStyle::LastOnly;
+            self.last_char_timer.start(ctx, Self::LAST_DIGIT_TIMEOUT);
+        } else {
+            self.last_char_timer.stop();
+            self.display_style = DisplayStyle::LastWithMarker;
+        }
+    }
+
+    fn on_erase(&mut self, ctx: &mut EventCtx, long_erase: bool) {
+        self.multi_tap.clear_pending_state(ctx);
+        if long_erase {
+            self.textbox.clear(ctx);
+        } else {
+            self.textbox.delete_last(ctx);
+        }
+        self.display_style = DisplayStyle::Hidden;
+    }
+
+    fn get_keypad_state(&self) -> KeypadState {
+        let mask = word_completion_mask(self.content(), self.wordlist);
+
+        // Disable each key whose entire letter group is illegal from here,
+        // rather than the whole keypad, so the guided-entry masking only
+        // greys out the groups that can't continue the current prefix.
+        let keys = core::array::from_fn(|i| {
+            if KEY_GROUPS[i].chars().any(|c| mask & letter_mask(c) != 0) {
+                ButtonState::Enabled
+            } else {
+                ButtonState::Disabled
+            }
+        });
+
+        // While the prefix has a unique completion, keep the pending
+        // multi-tap key enabled so its letter can still be cycled even if its
+        // group is masked; the word itself is auto-finished via `confirm`.
+        let override_key = self
+            .pending_completion()
+            .and_then(|_| self.multi_tap.pending_key())
+            .map(|k| (k, ButtonState::Enabled));
+
+        KeypadState {
+            back: ButtonState::Hidden,
+            erase: if self.textbox.len() == 0 {
+                ButtonState::Hidden
+            } else {
+                ButtonState::Enabled
+            },
+            cancel: if self.allow_cancel && self.textbox.len() == 0 {
+                ButtonState::Enabled
+            } else {
+                ButtonState::Hidden
+            },
+            confirm: if self.pending_completion().is_some() {
+                ButtonState::Enabled
+            } else {
+                ButtonState::Disabled
+            },
+            keys,
+            override_key,
+            // Guided wordlist entry always shows groups in their natural
+            // order — scrambling would fight the completion hints this
+            // mode exists to give.
+            key_order: None,
+        }
+    }
+
+    fn on_page_change(&mut self, ctx: &mut EventCtx) {
+        if self.multi_tap.pending_key().is_some() {
+            self.multi_tap.clear_pending_state(ctx);
+            self.display_style = DisplayStyle::LastOnly;
+            self.last_char_timer.start(ctx, Self::LAST_DIGIT_TIMEOUT);
+        }
+    }
+
+    fn content(&self) -> &str {
+        self.textbox.content()
+    }
+
+    fn is_full(&self) -> bool {
+        self.pending_completion().is_some() || self.textbox.len() >= Self::MAX_WORD_LEN
+    }
+
+    fn might_overlap_keypad(&self) -> bool {
+        false
+    }
+}
+
+impl Component for MnemonicInput {
+    type Msg = StringInputMsg;
+
+    fn place(&mut self, bounds: Rect) -> Rect {
+        self.area = bounds;
+        bounds
+    }
+
+    fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
+        match event {
+            Event::Timer(_) if self.multi_tap.timeout_event(event) => {
+                self.multi_tap.clear_pending_state(ctx);
+                self.last_char_timer.start(ctx, Self::LAST_DIGIT_TIMEOUT);
+                self.display_style = DisplayStyle::LastOnly;
+                return Some(StringInputMsg::UpdateKeypad);
+            }
+            Event::Timer(_) if self.last_char_timer.expire(event) => {
+                self.display_style = DisplayStyle::Hidden;
+                ctx.request_paint();
+            }
+            _ => {}
+        };
+        None
+    }
+
+    fn render<'s>(&self, target: &mut impl Renderer<'s>) {
+        if self.content().is_empty() {
+            return;
+        }
+        Text::new(self.area.left_center(), self.content(), Self::STYLE.text_font)
+            .with_align(Alignment::Start)
+            .with_fg(Self::STYLE.text_color)
+            .render(target);
+    }
+}
+
+#[cfg(feature = "ui_debug")]
+impl crate::trace::Trace for MnemonicInput {
+    fn trace(&self, t: &mut dyn crate::trace::Tracer) {
+        t.component("MnemonicInput");
+        t.string("content", self.content().into());
+        t.bool("allow_cancel", self.allow_cancel);
+        t.string(
+            "wordlist",
+            match self.wordlist {
+                WordlistKind::Bip39 => "bip39".into(),
+                WordlistKind::Slip39 => "slip39".into(),
+            },
+        );
+    }
+}
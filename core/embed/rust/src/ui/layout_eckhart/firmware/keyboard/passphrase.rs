@@ -1,6 +1,7 @@
 use crate::{
     strutil::TString,
     time::Duration,
+    trezorhal::random,
     ui::{
         component::{
             text::{
@@ -8,7 +9,7 @@ use crate::{
                 layout::{LayoutFit, LineBreaking},
                 TextStyle,
             },
-            Component, Event, EventCtx, TextLayout, Timer,
+            Component, Event, EventCtx, ScrollBar, TextLayout, Timer,
         },
         display::Icon,
         event::TouchEvent,
@@ -25,7 +26,7 @@ use super::super::{
             render_pending_marker, MultiTapKeyboard, FADING_ICON_COLORS, FADING_ICON_COUNT,
             KEYBOARD_INPUT_INSETS, KEYBOARD_INPUT_RADIUS, SHOWN_INSETS,
         },
-        keypad::{ButtonState, KeypadState},
+        keypad::{self, ButtonState, KeypadState},
     },
     theme, StringInput, StringInputMsg,
 };
@@ -40,6 +41,13 @@ pub struct PassphraseInput {
     multi_tap: MultiTapKeyboard,
     allow_cancel: bool,
     allow_empty: bool,
+    /// Which page of the revealed passphrase is shown, when the whole
+    /// passphrase doesn't fit within `shown_area` at once.
+    reveal_page: usize,
+    /// Per-session permutation the keypad renders its letter groups in, so
+    /// someone watching the screen can't learn key positions from shoulder
+    /// surfing across multiple entries.
+    key_order: [u8; keypad::KEY_COUNT],
 }
 
 impl PassphraseInput {
@@ -64,27 +72,178 @@ impl PassphraseInput {
             multi_tap: MultiTapKeyboard::new(),
             allow_cancel,
             allow_empty,
+            reveal_page: 0,
+            key_order: Self::shuffled_key_order(),
         }
     }
 
+    /// A random permutation of the keypad's letter-group slots, freshly
+    /// drawn each time a `PassphraseInput` is created.
+    fn shuffled_key_order() -> [u8; keypad::KEY_COUNT] {
+        let mut order: [u8; keypad::KEY_COUNT] = core::array::from_fn(|i| i as u8);
+        for i in (1..keypad::KEY_COUNT).rev() {
+            let j = random::uniform(i as u32 + 1) as usize;
+            order.swap(i, j);
+        }
+        order
+    }
+
+    /// Whether the passphrase needs more than one page to be shown in full.
+    fn is_paged(&self) -> bool {
+        matches!(
+            TextLayout::new(Self::STYLE)
+                .with_align(Alignment::Start)
+                .with_bounds(self.shown_area.inset(SHOWN_INSETS))
+                .fit_text(self.content()),
+            LayoutFit::OutOfBounds { .. }
+        )
+    }
+
+    /// Splits the inset `shown_area` into the one-line middle-ellipsis
+    /// summary pinned above the paged text, and the area the paged text
+    /// itself renders into, so the summary and the current page are always
+    /// visible together.
+    fn split_shown_area(&self) -> (Rect, Rect) {
+        self.shown_area
+            .inset(SHOWN_INSETS)
+            .split_top(Self::STYLE.text_font.line_height())
+    }
+
+    fn page_area(&self) -> Rect {
+        self.split_shown_area().1
+    }
+
+    /// The byte offset into `content()` where `page` begins, derived from
+    /// `TextLayout`'s own fit results rather than an assumed
+    /// characters-per-line constant, so pages always start exactly where the
+    /// proportional-font word-wrap actually breaks.
+    fn page_start(&self, page: usize) -> usize {
+        let bounds = self.page_area();
+        let content = self.content();
+        let mut offset = 0;
+        for _ in 0..page {
+            match TextLayout::new(Self::STYLE)
+                .with_align(Alignment::Start)
+                .with_bounds(bounds)
+                .fit_text(&content[offset..])
+            {
+                LayoutFit::OutOfBounds { processed_chars, .. } => {
+                    offset += processed_chars.max(1);
+                }
+                LayoutFit::Fitting { .. } => break,
+            }
+            if offset >= content.len() {
+                break;
+            }
+        }
+        offset.min(content.len())
+    }
+
+    fn page_count(&self) -> usize {
+        let bounds = self.page_area();
+        let content = self.content();
+        let mut offset = 0;
+        let mut pages = 1;
+        loop {
+            match TextLayout::new(Self::STYLE)
+                .with_align(Alignment::Start)
+                .with_bounds(bounds)
+                .fit_text(&content[offset..])
+            {
+                LayoutFit::OutOfBounds { processed_chars, .. } => {
+                    offset += processed_chars.max(1);
+                    pages += 1;
+                    if offset >= content.len() {
+                        break;
+                    }
+                }
+                LayoutFit::Fitting { .. } => break,
+            }
+        }
+        pages
+    }
+
+    /// Renders a single middle-truncated line keeping the leading and
+    /// trailing characters visible, so the user can verify both ends of a
+    /// passphrase that's too long to show on a single page.
+    fn render_middle_ellipsis<'s>(&self, bounds: Rect, target: &mut impl Renderer<'s>) {
+        const ELLIPSIS: &str = "...";
+
+        let content = self.content();
+
+        let ellipsis_width = Self::STYLE.text_font.text_width(ELLIPSIS);
+        let total_budget = (bounds.width() - ellipsis_width).max(0);
+        let mut lead_budget = total_budget / 2;
+        let mut trail_budget = total_budget - lead_budget;
+
+        let mut lead = 0;
+        for c in content.chars() {
+            let w = Self::STYLE.text_font.char_width(c);
+            if lead_budget < w {
+                break;
+            }
+            lead_budget -= w;
+            lead += c.len_utf8();
+        }
+
+        let mut trail = 0;
+        for c in content.chars().rev() {
+            let w = Self::STYLE.text_font.char_width(c);
+            if trail_budget < w || lead + trail + c.len_utf8() > content.len() {
+                break;
+            }
+            trail_budget -= w;
+            trail += c.len_utf8();
+        }
+
+        if lead + trail >= content.len() {
+            // The leading and trailing windows cover the whole passphrase;
+            // no truncation needed after all.
+            TextLayout::new(Self::STYLE)
+                .with_bounds(bounds)
+                .with_align(Alignment::Start)
+                .render_text(content, target, true);
+            return;
+        }
+
+        let head = &content[..lead];
+        let tail = &content[content.len() - trail..];
+        let truncated: crate::strutil::ShortString = uformat!("{}{}{}", head, ELLIPSIS, tail);
+
+        TextLayout::new(Self::STYLE)
+            .with_bounds(bounds)
+            .with_align(Alignment::Start)
+            .render_text(truncated.as_str(), target, true);
+    }
+
     fn update_shown_area(&mut self) {
-        // The area where the passphrase is shown
+        // The area where the passphrase is shown, capped to the screen so it
+        // never renders past the bottom of the display.
         let mut shown_area = Rect::from_top_left_and_size(
             self.area.top_left(),
             Offset::new(SCREEN.width(), self.area.height()),
         )
         .inset(KEYBOARD_INPUT_INSETS);
 
-        // Extend the shown area until the text fits
-        while let LayoutFit::OutOfBounds { .. } = TextLayout::new(Self::STYLE)
-            .with_align(Alignment::Start)
-            .with_bounds(shown_area.inset(SHOWN_INSETS))
-            .fit_text(self.content())
-        {
+        // Extend the shown area until the text fits, or until we run out of
+        // screen, at which point `render_shown` switches to a paged view.
+        while shown_area.height() < SCREEN.height() {
+            let fits = matches!(
+                TextLayout::new(Self::STYLE)
+                    .with_align(Alignment::Start)
+                    .with_bounds(shown_area.inset(SHOWN_INSETS))
+                    .fit_text(self.content()),
+                LayoutFit::Fitting { .. }
+            );
+            if fits {
+                break;
+            }
             shown_area = shown_area.outset(Insets::bottom(Self::STYLE.text_font.line_height()));
         }
+        shown_area = shown_area.clamp(SCREEN);
 
         self.shown_area = shown_area;
+        self.reveal_page = self.reveal_page.min(self.page_count().saturating_sub(1));
     }
 
     fn render_shown<'s>(&self, target: &mut impl Renderer<'s>) {
@@ -96,10 +255,31 @@ impl PassphraseInput {
             .with_radius(KEYBOARD_INPUT_RADIUS)
             .render(target);
 
-        TextLayout::new(Self::STYLE)
-            .with_bounds(self.shown_area.inset(SHOWN_INSETS))
-            .with_align(Alignment::Start)
-            .render_text(self.content(), target, true);
+        if self.is_paged() {
+            // Always keep a one-line middle-ellipsis summary visible above
+            // the current page, so the start and end of the passphrase can
+            // be verified at a glance no matter which page is shown.
+            let (summary_area, page_area) = self.split_shown_area();
+            self.render_middle_ellipsis(summary_area, target);
+
+            let skip_bytes = self.page_start(self.reveal_page);
+            let page_text = &self.content()[skip_bytes..];
+
+            TextLayout::new(Self::STYLE)
+                .with_bounds(page_area)
+                .with_align(Alignment::Start)
+                .render_text(page_text, target, true);
+
+            ScrollBar::vertical()
+                .with_page_count(self.page_count())
+                .with_active_page(self.reveal_page)
+                .render(self.shown_area, target);
+        } else {
+            TextLayout::new(Self::STYLE)
+                .with_bounds(self.shown_area.inset(SHOWN_INSETS))
+                .with_align(Alignment::Start)
+                .render_text(self.content(), target, true);
+        }
     }
 
     fn render_hidden<'s>(&self, target: &mut impl Renderer<'s>) {
@@ -211,8 +391,9 @@ impl StringInput for PassphraseInput {
                 erase: ButtonState::Disabled,
                 cancel: ButtonState::Hidden,
                 confirm: ButtonState::Disabled,
-                keys: ButtonState::Disabled,
+                keys: [ButtonState::Disabled; keypad::KEY_COUNT],
                 override_key: None,
+                key_order: Some(self.key_order),
             }
         } else if self.is_full() {
             // Disable all except of confirm, erase and the pending key if there is some
@@ -226,8 +407,9 @@ impl StringInput for PassphraseInput {
                 erase: ButtonState::Enabled,
                 cancel: ButtonState::Hidden,
                 confirm: ButtonState::Enabled,
-                keys: ButtonState::Disabled,
+                keys: [ButtonState::Disabled; keypad::KEY_COUNT],
                 override_key,
+                key_order: Some(self.key_order),
             }
         } else if self.is_empty() {
             // Disable all except of confirm and erase buttons
@@ -244,8 +426,9 @@ impl StringInput for PassphraseInput {
                 } else {
                     ButtonState::Disabled
                 },
-                keys: ButtonState::Enabled,
+                keys: [ButtonState::Enabled; keypad::KEY_COUNT],
                 override_key: None,
+                key_order: Some(self.key_order),
             }
         } else {
             KeypadState {
@@ -253,8 +436,9 @@ impl StringInput for PassphraseInput {
                 erase: ButtonState::Enabled,
                 cancel: ButtonState::Hidden,
                 confirm: ButtonState::Enabled,
-                keys: ButtonState::Enabled,
+                keys: [ButtonState::Enabled; keypad::KEY_COUNT],
                 override_key: None,
+                key_order: Some(self.key_order),
             }
         }
     }
@@ -321,6 +505,7 @@ impl Component for PassphraseInput {
                 self.last_char_timer.stop();
                 // Show the entire passphrase on the touch start
                 self.display_style = DisplayStyle::Shown;
+                self.reveal_page = 0;
                 self.update_shown_area();
                 return Some(StringInputMsg::UpdateKeypad);
             }
@@ -342,6 +527,26 @@ impl Component for PassphraseInput {
                 self.display_style = DisplayStyle::Hidden;
                 return Some(StringInputMsg::UpdateKeypad);
             }
+            // Dragging inside the extended area while paged scrolls through
+            // the revealed passphrase page by page.
+            Event::Touch(TouchEvent::TouchMove(pos))
+                if self.display_style == DisplayStyle::Shown && self.is_paged() =>
+            {
+                // Anchored to `page_area()`'s real height rather than the
+                // full screen, so the drag's range actually spans every
+                // page instead of bottoming out partway through a long
+                // passphrase.
+                let page_area = self.page_area();
+                let page_count = self.page_count();
+                let height = page_area.height().max(1) as usize;
+                let offset = (pos.y - page_area.y0).max(0) as usize;
+                let page = (offset * page_count / height).min(page_count.saturating_sub(1));
+                if page != self.reveal_page {
+                    self.reveal_page = page;
+                    ctx.request_paint();
+                }
+                return None;
+            }
             // Timeout for showing the last char.
             Event::Timer(_) if self.last_char_timer.expire(event) => {
                 self.display_style = DisplayStyle::Hidden;